@@ -0,0 +1,67 @@
+/// Microphone level metering: subscribes to the backend's amplitude stream
+/// and re-broadcasts it to the webview as `audio-level` events.
+use crate::socket_client::SOCKET_PATH;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use tokio::sync::Mutex;
+use tokio::time::{Duration, Instant};
+
+/// Minimum gap between `audio-level` emissions, giving a smooth ~30-60Hz VU
+/// meter without flooding the IPC bridge.
+const EMIT_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Delay before retrying a dropped `level` subscription.
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Holds the most recently observed microphone level (0.0-1.0) so it can be
+/// polled via `get_audio_level` in addition to the pushed `audio-level` event.
+#[derive(Default)]
+pub struct AudioLevelState(pub Mutex<f32>);
+
+/// Gets the most recently observed microphone level.
+#[tauri::command]
+pub async fn get_audio_level(state: tauri::State<'_, Arc<AudioLevelState>>) -> Result<f32, String> {
+    Ok(*state.0.lock().await)
+}
+
+/// Opens the `level` subscription to the backend and keeps it alive for the
+/// lifetime of the app, reconnecting after a delay if the backend drops it.
+pub fn start_level_stream(app: AppHandle, state: Arc<AudioLevelState>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if let Err(e) = subscribe_level(&app, &state).await {
+                log::warn!("Audio level stream disconnected: {}", e);
+            }
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+    });
+}
+
+/// Connects to the backend, subscribes to amplitude samples, and emits a
+/// debounced `audio-level` event for each one until the connection drops.
+async fn subscribe_level(app: &AppHandle, state: &Arc<AudioLevelState>) -> Result<(), std::io::Error> {
+    let stream = UnixStream::connect(SOCKET_PATH).await?;
+    let (reader, mut writer) = stream.into_split();
+    writer.write_all(b"level").await?;
+    writer.flush().await?;
+
+    let mut lines = BufReader::new(reader).lines();
+    let mut last_emit = Instant::now() - EMIT_INTERVAL;
+
+    while let Some(line) = lines.next_line().await? {
+        let Ok(level) = line.trim().parse::<f32>() else {
+            continue;
+        };
+        let level = level.clamp(0.0, 1.0);
+        *state.0.lock().await = level;
+
+        if last_emit.elapsed() >= EMIT_INTERVAL {
+            last_emit = Instant::now();
+            let _ = app.emit("audio-level", level);
+        }
+    }
+
+    Ok(())
+}
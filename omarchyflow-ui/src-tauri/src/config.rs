@@ -0,0 +1,148 @@
+/// Persistent user configuration, stored as JSON in the platform config directory.
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Default global shortcut used until the user configures one.
+pub const DEFAULT_HOTKEY: &str = "CommandOrControl+Shift+Space";
+/// Default amplitude threshold (0.0-1.0) above which the mic is considered active.
+pub const DEFAULT_MIC_THRESHOLD: f32 = 0.15;
+
+/// User-configurable application settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    /// Global shortcut bound to recording (toggle or push-to-talk).
+    #[serde(default = "default_hotkey")]
+    pub hotkey: String,
+    /// Microphone amplitude threshold (0.0-1.0) used for voice-activated recording.
+    #[serde(default = "default_mic_threshold")]
+    pub mic_threshold: f32,
+    /// When true, `hotkey` is held down to record (push-to-talk) instead of toggling it.
+    #[serde(default)]
+    pub push_to_talk: bool,
+    /// When true, recording starts/stops automatically based on mic activity
+    /// instead of (or alongside) the hotkey.
+    #[serde(default)]
+    pub auto_record: bool,
+    /// How long the level must stay above `mic_threshold` before auto-record starts.
+    #[serde(default = "default_attack_ms")]
+    pub attack_ms: u64,
+    /// How long the level must stay below `mic_threshold` before auto-record stops.
+    #[serde(default = "default_release_ms")]
+    pub release_ms: u64,
+    /// Start minimized to the system tray instead of showing the main window.
+    #[serde(default)]
+    pub start_minimized: bool,
+    /// Directory recordings are written to. `None` uses the backend's default.
+    /// Forwarded to the sidecar as an env var by `supervisor` on each
+    /// (re)spawn; a change here only takes effect the next time the backend
+    /// restarts.
+    #[serde(default)]
+    pub output_dir: Option<PathBuf>,
+}
+
+fn default_hotkey() -> String {
+    DEFAULT_HOTKEY.to_string()
+}
+
+fn default_mic_threshold() -> f32 {
+    DEFAULT_MIC_THRESHOLD
+}
+
+fn default_attack_ms() -> u64 {
+    150
+}
+
+fn default_release_ms() -> u64 {
+    1500
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            hotkey: default_hotkey(),
+            mic_threshold: default_mic_threshold(),
+            push_to_talk: false,
+            auto_record: false,
+            attack_ms: default_attack_ms(),
+            release_ms: default_release_ms(),
+            start_minimized: false,
+            output_dir: None,
+        }
+    }
+}
+
+/// Returns the app's config directory, creating it if it doesn't exist yet.
+fn config_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve config directory: {}", e))?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    Ok(dir)
+}
+
+fn config_path(app: &AppHandle) -> Result<PathBuf, String> {
+    Ok(config_dir(app)?.join("config.json"))
+}
+
+/// Loads the config from disk, falling back to defaults if it's missing or invalid.
+pub fn load_config(app: &AppHandle) -> Config {
+    let path = match config_path(app) {
+        Ok(path) => path,
+        Err(e) => {
+            log::warn!("{}", e);
+            return Config::default();
+        }
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+            log::warn!("Failed to parse config, using defaults: {}", e);
+            Config::default()
+        }),
+        Err(_) => Config::default(),
+    }
+}
+
+/// Saves the config to disk.
+pub fn save_config(app: &AppHandle, config: &Config) -> Result<(), String> {
+    let path = config_path(app)?;
+    let contents = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write config: {}", e))
+}
+
+/// Gets the current configuration.
+#[tauri::command]
+pub async fn get_config(
+    state: tauri::State<'_, Arc<tauri::async_runtime::Mutex<Config>>>,
+) -> Result<Config, String> {
+    Ok(state.lock().await.clone())
+}
+
+/// Sets the configuration and persists it to disk. If `hotkey` changed, the
+/// global shortcut is re-registered so it matches what `get_config` reports,
+/// the same thing `hotkey::set_hotkey` does for a hotkey-only update.
+#[tauri::command]
+pub async fn set_config(
+    app: AppHandle,
+    state: tauri::State<'_, Arc<tauri::async_runtime::Mutex<Config>>>,
+    config: Config,
+) -> Result<(), String> {
+    let mut current = state.lock().await;
+
+    if config.hotkey != current.hotkey {
+        if let Err(e) = crate::hotkey::unregister(&app, &current.hotkey) {
+            log::warn!("{}", e);
+        }
+        crate::hotkey::register(&app, &config.hotkey)?;
+    }
+
+    save_config(&app, &config)?;
+    *current = config;
+    Ok(())
+}
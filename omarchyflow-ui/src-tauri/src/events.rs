@@ -0,0 +1,88 @@
+/// Backend event synchronization. Keeps a connection to the backend open to
+/// read unsolicited status lines (`recording_started`, `recording_stopped`,
+/// `error`, ...) so `RecordingState` stays authoritative even if the backend
+/// starts, stops or crashes on its own.
+use crate::socket_client::SOCKET_PATH;
+use crate::RecordingState;
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::net::UnixStream;
+use tokio::sync::Mutex;
+use tokio::time::Duration;
+
+/// Delay before retrying a dropped event connection.
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// A status notification pushed unsolicited by the backend, forwarded to the
+/// webview verbatim as the `backend-event` event.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackendEvent {
+    pub name: String,
+    pub payload: Option<String>,
+}
+
+impl BackendEvent {
+    /// Parses a `name` or `name:payload` status line from the backend.
+    fn parse(line: &str) -> Self {
+        match line.split_once(':') {
+            Some((name, payload)) => Self {
+                name: name.trim().to_string(),
+                payload: Some(payload.trim().to_string()),
+            },
+            None => Self {
+                name: line.trim().to_string(),
+                payload: None,
+            },
+        }
+    }
+}
+
+/// Backend connectivity and activity state, as distinguished by
+/// `check_backend_status`: a reachable socket that isn't recording is
+/// "idle", not "disconnected".
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendStatus {
+    Disconnected,
+    Idle,
+    Recording,
+}
+
+/// Keeps a connection to the backend's event stream open for the lifetime of
+/// the app, reconnecting after a delay if the backend drops it.
+pub fn start_event_listener(app: AppHandle, recording_state: Arc<Mutex<RecordingState>>) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if let Err(e) = listen(&app, &recording_state).await {
+                log::warn!("Backend event stream disconnected: {}", e);
+            }
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+    });
+}
+
+/// Reads status lines from the backend until the connection drops, updating
+/// `RecordingState` and forwarding each one to the webview.
+async fn listen(
+    app: &AppHandle,
+    recording_state: &Arc<Mutex<RecordingState>>,
+) -> Result<(), std::io::Error> {
+    let stream = UnixStream::connect(SOCKET_PATH).await?;
+    let mut lines = BufReader::new(stream).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let event = BackendEvent::parse(&line);
+
+        match event.name.as_str() {
+            "recording_started" => recording_state.lock().await.is_recording = true,
+            "recording_stopped" => recording_state.lock().await.is_recording = false,
+            _ => {}
+        }
+
+        let _ = app.emit("backend-event", &event);
+    }
+
+    Ok(())
+}
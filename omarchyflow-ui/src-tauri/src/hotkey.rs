@@ -0,0 +1,81 @@
+/// Global shortcut handling: binds the configured key to recording, either as
+/// a toggle or, in push-to-talk mode, as press-to-start/release-to-stop.
+use crate::config::{save_config, Config};
+use crate::RecordingState;
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tokio::sync::Mutex;
+
+/// Builds the global shortcut plugin, dispatching key events to start, stop or
+/// toggle recording depending on the current push-to-talk setting.
+pub fn plugin(
+    recording_state: Arc<Mutex<RecordingState>>,
+    config: Arc<Mutex<Config>>,
+) -> tauri::plugin::TauriPlugin<tauri::Wry> {
+    tauri_plugin_global_shortcut::Builder::new()
+        .with_handler(move |_app, _shortcut, event| {
+            let recording_state = recording_state.clone();
+            let config = config.clone();
+            match event.state() {
+                ShortcutState::Pressed => {
+                    tauri::async_runtime::spawn(async move {
+                        if config.lock().await.push_to_talk {
+                            crate::start_if_stopped(&recording_state).await;
+                        } else {
+                            let _ = crate::toggle_recording_state(&recording_state).await;
+                        }
+                    });
+                }
+                ShortcutState::Released => {
+                    tauri::async_runtime::spawn(async move {
+                        if config.lock().await.push_to_talk {
+                            crate::stop_if_recording(&recording_state).await;
+                        }
+                    });
+                }
+            }
+        })
+        .build()
+}
+
+/// Registers a shortcut string as the active global hotkey.
+pub fn register(app: &AppHandle, shortcut_str: &str) -> Result<(), String> {
+    let shortcut: Shortcut = shortcut_str
+        .parse()
+        .map_err(|e| format!("Invalid shortcut '{}': {}", shortcut_str, e))?;
+    app.global_shortcut()
+        .register(shortcut)
+        .map_err(|e| format!("Failed to register shortcut '{}': {}", shortcut_str, e))
+}
+
+/// Unregisters a previously-registered shortcut string.
+pub fn unregister(app: &AppHandle, shortcut_str: &str) -> Result<(), String> {
+    let shortcut: Shortcut = shortcut_str
+        .parse()
+        .map_err(|e| format!("Invalid shortcut '{}': {}", shortcut_str, e))?;
+    app.global_shortcut()
+        .unregister(shortcut)
+        .map_err(|e| format!("Failed to unregister shortcut '{}': {}", shortcut_str, e))
+}
+
+/// Rebinds the global hotkey: unregisters the old shortcut, registers the new
+/// one, and persists it to config. A tiling WM user can use this to pick a
+/// combo that doesn't collide with their compositor's own bindings.
+#[tauri::command]
+pub async fn set_hotkey(
+    app: AppHandle,
+    config: tauri::State<'_, Arc<Mutex<Config>>>,
+    shortcut: String,
+) -> Result<(), String> {
+    let mut current = config.lock().await;
+
+    if let Err(e) = unregister(&app, &current.hotkey) {
+        log::warn!("{}", e);
+    }
+    register(&app, &shortcut)?;
+
+    current.hotkey = shortcut;
+    save_config(&app, &current)?;
+    Ok(())
+}
@@ -1,12 +1,19 @@
 /// Main Tauri application entry point.
+mod audio_meter;
+mod config;
 mod error;
+mod events;
+mod hotkey;
 mod socket_client;
+mod supervisor;
+mod vad;
 
+use audio_meter::AudioLevelState;
 use socket_client::{is_backend_running, send_command};
+use std::sync::Arc;
 use tauri::{
     AppHandle, Manager, State, Window,
 };
-use tauri_plugin_shell::ShellExt;
 
 /// State to track recording status.
 #[derive(Default)]
@@ -48,10 +55,68 @@ async fn stop_recording() -> Result<(), String> {
 /// or an error message if the operation failed.
 #[tauri::command]
 async fn toggle_recording(
-    state: State<'_, tauri::async_runtime::Mutex<RecordingState>>,
+    state: State<'_, Arc<tauri::async_runtime::Mutex<RecordingState>>>,
 ) -> Result<bool, String> {
+    toggle_recording_state(&*state).await
+}
+
+/// Starts recording if it isn't already active, updating `state` on success.
+/// Holds the lock across the `send_command` call so the check-and-set is
+/// atomic against any other caller racing to start/stop/toggle at the same
+/// time. Shared by the push-to-talk hotkey handler and VAD.
+///
+/// Returns `true` if this call actually started recording.
+pub(crate) async fn start_if_stopped(
+    state: &Arc<tauri::async_runtime::Mutex<RecordingState>>,
+) -> bool {
+    let mut recording_state = state.lock().await;
+    if recording_state.is_recording {
+        return false;
+    }
+    match send_command("start").await {
+        Ok(_) => {
+            recording_state.is_recording = true;
+            true
+        }
+        Err(e) => {
+            log::error!("Failed to start recording: {}", e);
+            false
+        }
+    }
+}
+
+/// Stops recording if currently active, updating `state` on success. The
+/// push-to-talk/VAD counterpart to [`start_if_stopped`], with the same
+/// atomic check-and-set guarantee.
+///
+/// Returns `true` if this call actually stopped recording.
+pub(crate) async fn stop_if_recording(
+    state: &Arc<tauri::async_runtime::Mutex<RecordingState>>,
+) -> bool {
     let mut recording_state = state.lock().await;
-    
+    if !recording_state.is_recording {
+        return false;
+    }
+    match send_command("stop").await {
+        Ok(_) => {
+            recording_state.is_recording = false;
+            true
+        }
+        Err(e) => {
+            log::error!("Failed to stop recording: {}", e);
+            false
+        }
+    }
+}
+
+/// Toggles recording, updating `state` on success. Backs both the
+/// `toggle_recording` IPC command and the non-push-to-talk hotkey handler so
+/// the two can't drift out of sync.
+pub(crate) async fn toggle_recording_state(
+    state: &Arc<tauri::async_runtime::Mutex<RecordingState>>,
+) -> Result<bool, String> {
+    let mut recording_state = state.lock().await;
+
     let command = if recording_state.is_recording {
         "stop"
     } else {
@@ -70,24 +135,35 @@ async fn toggle_recording(
 ///
 /// # Returns
 ///
-/// Returns `true` if recording is active, `false` otherwise.
-/// Note: This returns the local state, not the actual backend state.
+/// Returns `true` if recording is active, `false` otherwise. Kept in sync
+/// with the backend by the `backend-event` listener started in `setup`.
 #[tauri::command]
 async fn get_recording_status(
-    state: State<'_, tauri::async_runtime::Mutex<RecordingState>>,
+    state: State<'_, Arc<tauri::async_runtime::Mutex<RecordingState>>>,
 ) -> Result<bool, String> {
     let recording_state = state.lock().await;
     Ok(recording_state.is_recording)
 }
 
-/// Checks if the backend is running.
+/// Checks the backend's connectivity and activity state.
 ///
 /// # Returns
 ///
-/// Returns `true` if the backend socket is accessible, `false` otherwise.
+/// `Disconnected` if the socket isn't reachable, `Recording` if the backend
+/// is actively capturing audio, or `Idle` if it's reachable but not recording.
 #[tauri::command]
-async fn check_backend_status() -> Result<bool, String> {
-    Ok(is_backend_running().await)
+async fn check_backend_status(
+    state: State<'_, Arc<tauri::async_runtime::Mutex<RecordingState>>>,
+) -> Result<events::BackendStatus, String> {
+    if !is_backend_running().await {
+        return Ok(events::BackendStatus::Disconnected);
+    }
+
+    if state.lock().await.is_recording {
+        Ok(events::BackendStatus::Recording)
+    } else {
+        Ok(events::BackendStatus::Idle)
+    }
 }
 
 /// Shows the main window.
@@ -147,7 +223,6 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
-        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .setup(|app| {
             // Initialize plugins
             if cfg!(debug_assertions) {
@@ -160,8 +235,40 @@ pub fn run() {
             app.handle().plugin(tauri_plugin_fs::init())?;
             app.handle().plugin(tauri_plugin_shell::init())?;
 
-            // Initialize recording state
-            app.manage(tauri::async_runtime::Mutex::new(RecordingState::default()));
+            // Load user config and keep it available to commands
+            let config = config::load_config(app.handle());
+            let start_minimized = config.start_minimized;
+            let initial_hotkey = config.hotkey.clone();
+            let config_state = Arc::new(tauri::async_runtime::Mutex::new(config));
+            app.manage(config_state.clone());
+
+            // Initialize recording state and start listening for the backend's
+            // own status events so it stays authoritative
+            let recording_state = Arc::new(tauri::async_runtime::Mutex::new(RecordingState::default()));
+            app.manage(recording_state.clone());
+            events::start_event_listener(app.handle().clone(), recording_state.clone());
+
+            // Register the global shortcut plugin and bind the configured hotkey.
+            // Registered here (rather than via `.plugin()` on the builder) because
+            // the handler needs the config and recording state created above.
+            app.handle()
+                .plugin(hotkey::plugin(recording_state.clone(), config_state.clone()))?;
+            if let Err(e) = hotkey::register(app.handle(), &initial_hotkey) {
+                log::error!("Failed to register initial hotkey '{}': {}", initial_hotkey, e);
+            }
+
+            // Initialize audio level state and start the metering stream
+            let audio_level_state = Arc::new(AudioLevelState::default());
+            app.manage(audio_level_state.clone());
+            audio_meter::start_level_stream(app.handle().clone(), audio_level_state.clone());
+
+            // Start voice-activated recording (no-op unless config.auto_record is set)
+            vad::start(
+                app.handle().clone(),
+                audio_level_state,
+                recording_state.clone(),
+                config_state.clone(),
+            );
 
             // Setup system tray
             setup_tray(app.handle())?;
@@ -171,17 +278,21 @@ pub fn run() {
                 .get_webview_window("main")
                 .ok_or("Main window not found")?;
 
-            // Show window on startup (user can hide it if they want)
-            window.show().map_err(|e| {
-                format!("Failed to show window on startup: {}", e)
-            })?;
-            
-            // Try to bring window to front
-            window.set_focus().map_err(|e| {
-                format!("Failed to focus window on startup: {}", e)
-            })?;
-            
-            log::info!("Window shown and focused");
+            // Show window on startup, unless the user configured the app to start minimized
+            if start_minimized {
+                log::info!("Starting minimized to tray (per config)");
+            } else {
+                window.show().map_err(|e| {
+                    format!("Failed to show window on startup: {}", e)
+                })?;
+
+                // Try to bring window to front
+                window.set_focus().map_err(|e| {
+                    format!("Failed to focus window on startup: {}", e)
+                })?;
+
+                log::info!("Window shown and focused");
+            }
 
             // Handle window close - minimize to tray instead of quitting
             let window_handle = window.clone();
@@ -192,37 +303,10 @@ pub fn run() {
                 }
             });
 
-            // Spawn Python backend as sidecar
-            let handle = app.handle().clone();
-            tauri::async_runtime::spawn(async move {
-                match handle
-                    .shell()
-                    .sidecar("omarchyflow-backend")
-                    .map_err(|e| format!("Failed to create sidecar: {}", e))
-                {
-                    Ok(sidecar) => {
-                        match sidecar.spawn() {
-                            Ok((mut rx, _child)) => {
-                                while let Some(event) = rx.recv().await {
-                                    if let tauri_plugin_shell::process::CommandEvent::Stdout(line) =
-                                        event
-                                    {
-                                        if let Ok(text) = String::from_utf8(line) {
-                                            log::info!("Backend: {}", text);
-                                        }
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                log::error!("Failed to spawn backend: {}", e);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        log::error!("{}", e);
-                    }
-                }
-            });
+            // Spawn the Python backend as a supervised sidecar, restarting it
+            // with backoff if it crashes or exits. Passed the config state so
+            // it can forward settings (e.g. output_dir) to the sidecar.
+            supervisor::start(app.handle().clone(), config_state.clone());
 
             Ok(())
         })
@@ -233,7 +317,11 @@ pub fn run() {
             get_recording_status,
             check_backend_status,
             show_window,
-            hide_window
+            hide_window,
+            audio_meter::get_audio_level,
+            config::get_config,
+            config::set_config,
+            hotkey::set_hotkey
         ])
         .run(tauri::generate_context!())
         .map_err(|e| {
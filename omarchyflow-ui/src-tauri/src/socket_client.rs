@@ -0,0 +1,159 @@
+/// Unix socket client for communicating with the Python backend.
+use crate::error::SocketError;
+use std::path::Path;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use tokio::time::{timeout, Duration};
+
+/// Path to the Unix socket used for communication with the Python backend.
+pub(crate) const SOCKET_PATH: &str = "/tmp/voice-dictation.sock";
+/// Timeout for socket operations in seconds.
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(2);
+/// Timeout waiting for the backend's reply line, kept short and separate
+/// from `SOCKET_TIMEOUT`: the command has already been written and flushed
+/// by this point, and some backend builds don't send a reply at all, so a
+/// slow/absent reply shouldn't make the whole call block for 2 seconds.
+const REPLY_TIMEOUT: Duration = Duration::from_millis(300);
+/// Number of reconnect attempts `send_command` makes before giving up.
+const MAX_RETRIES: u32 = 3;
+/// Delay between reconnect attempts.
+const RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// Number of times in a row `send_command` has exhausted all its retries.
+/// A sidecar that's alive but whose socket is missing or erroring never
+/// emits `CommandEvent::Terminated`, so the supervisor polls this counter to
+/// notice the backend is unreachable and force a restart. Only connect, send
+/// and read errors count; a backend that accepts the connection but never
+/// replies is treated as delivered (see `REPLY_TIMEOUT`) and resets it.
+static CONSECUTIVE_FAILURES: AtomicU32 = AtomicU32::new(0);
+
+/// Consecutive `send_command` failures the supervisor tolerates before it
+/// treats the backend as wedged and force-restarts it.
+pub const FAILURE_THRESHOLD: u32 = 3;
+
+/// Returns how many times in a row `send_command` has failed outright.
+pub fn consecutive_failures() -> u32 {
+    CONSECUTIVE_FAILURES.load(Ordering::Relaxed)
+}
+
+/// Resets the consecutive-failure counter, e.g. once the supervisor has
+/// restarted the backend and wants to give the new instance a clean slate.
+pub fn reset_consecutive_failures() {
+    CONSECUTIVE_FAILURES.store(0, Ordering::Relaxed);
+}
+
+/// Sends a command to the Python backend via Unix socket, transparently
+/// reconnecting up to `MAX_RETRIES` times if the socket is momentarily
+/// unreachable (e.g. the backend is mid-restart).
+///
+/// # Arguments
+///
+/// * `command` - The command to send ("start", "stop", or "toggle")
+///
+/// # Returns
+///
+/// Returns the backend's reply line (empty if it didn't send one in time),
+/// or an error if communication failed.
+///
+/// # Errors
+///
+/// Returns `SocketError` if every attempt fails:
+/// - The socket cannot be connected to
+/// - The command cannot be sent
+/// - The reply socket errors out (not merely slow/silent - see `REPLY_TIMEOUT`)
+pub async fn send_command(command: &str) -> Result<String, SocketError> {
+    // Validate command
+    if !matches!(command, "start" | "stop" | "toggle") {
+        return Err(SocketError::InvalidCommand(format!(
+            "Invalid command: {}. Must be 'start', 'stop', or 'toggle'",
+            command
+        )));
+    }
+
+    let mut last_err = SocketError::BackendNotRunning;
+    for attempt in 0..=MAX_RETRIES {
+        if attempt > 0 {
+            tokio::time::sleep(RETRY_DELAY).await;
+        }
+
+        match try_send(command).await {
+            Ok(reply) => {
+                CONSECUTIVE_FAILURES.store(0, Ordering::Relaxed);
+                return Ok(reply);
+            }
+            Err(e) => {
+                log::warn!(
+                    "send_command('{}') attempt {}/{} failed: {}",
+                    command,
+                    attempt + 1,
+                    MAX_RETRIES + 1,
+                    e
+                );
+                last_err = e;
+            }
+        }
+    }
+
+    CONSECUTIVE_FAILURES.fetch_add(1, Ordering::Relaxed);
+    Err(last_err)
+}
+
+/// Makes a single connect/send/read attempt against the backend socket.
+async fn try_send(command: &str) -> Result<String, SocketError> {
+    // Check if socket exists
+    if !Path::new(SOCKET_PATH).exists() {
+        return Err(SocketError::BackendNotRunning);
+    }
+
+    // Connect to socket with timeout
+    let mut stream = timeout(SOCKET_TIMEOUT, UnixStream::connect(SOCKET_PATH))
+        .await
+        .map_err(|_| SocketError::ConnectionFailed("Connection timeout".to_string()))?
+        .map_err(|e| SocketError::ConnectionFailed(e.to_string()))?;
+
+    // Send command with timeout
+    let command_bytes = command.as_bytes();
+    timeout(SOCKET_TIMEOUT, stream.write_all(command_bytes))
+        .await
+        .map_err(|_| SocketError::SendFailed("Send timeout".to_string()))?
+        .map_err(|e| SocketError::SendFailed(e.to_string()))?;
+
+    // Flush to ensure data is sent
+    timeout(SOCKET_TIMEOUT, stream.flush())
+        .await
+        .map_err(|_| SocketError::SendFailed("Flush timeout".to_string()))?
+        .map_err(|e| SocketError::SendFailed(e.to_string()))?;
+
+    // Read the backend's reply so callers see its actual state when one is
+    // sent. The command itself was already written and flushed above, so a
+    // reply that doesn't show up within REPLY_TIMEOUT is treated as "the
+    // backend doesn't talk back for this command", not as a failure.
+    let mut reader = BufReader::new(stream);
+    let mut reply = String::new();
+    match timeout(REPLY_TIMEOUT, reader.read_line(&mut reply)).await {
+        Ok(Ok(_)) => Ok(reply.trim().to_string()),
+        Ok(Err(e)) => Err(SocketError::ReadFailed(e.to_string())),
+        Err(_) => {
+            log::debug!(
+                "No reply from backend for '{}' within {:?}; treating as delivered",
+                command,
+                REPLY_TIMEOUT
+            );
+            Ok(String::new())
+        }
+    }
+}
+
+/// Checks if the backend is running by attempting to connect to the socket.
+pub async fn is_backend_running() -> bool {
+    if !Path::new(SOCKET_PATH).exists() {
+        return false;
+    }
+
+    // Actually try to connect - stale socket files can exist after crashes
+    timeout(Duration::from_millis(500), UnixStream::connect(SOCKET_PATH))
+        .await
+        .map(|r| r.is_ok())
+        .unwrap_or(false)
+}
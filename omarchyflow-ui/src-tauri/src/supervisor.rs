@@ -0,0 +1,113 @@
+/// Backend process supervision: spawns the Python backend sidecar and
+/// restarts it with capped exponential backoff if it exits, crashes, or
+/// stops responding, emitting a `backend-status` event for each transition.
+use crate::config::Config;
+use crate::socket_client;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_shell::process::CommandEvent;
+use tauri_plugin_shell::ShellExt;
+use tokio::sync::Mutex;
+use tokio::time::{interval, Duration};
+
+/// Delay before the first restart attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+/// Longest delay between restart attempts.
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// How often the watchdog checks for a wedged (alive but unresponsive) backend.
+const WATCHDOG_INTERVAL: Duration = Duration::from_secs(5);
+/// Env var the sidecar reads `config.output_dir` from, when set.
+const OUTPUT_DIR_ENV: &str = "OMARCHYFLOW_OUTPUT_DIR";
+
+/// Spawns the sidecar and supervises it for the lifetime of the app,
+/// restarting it whenever it terminates or the watchdog finds it wedged.
+pub fn start(app: AppHandle, config: Arc<Mutex<Config>>) {
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = INITIAL_BACKOFF;
+
+        loop {
+            emit_status(&app, "starting");
+
+            match run_until_exit(&app, &config).await {
+                Ok(()) => {
+                    log::info!("Backend exited cleanly");
+                    backoff = INITIAL_BACKOFF;
+                }
+                Err(e) => {
+                    log::warn!("Backend crashed: {}", e);
+                    emit_status(&app, "crashed");
+                }
+            }
+
+            emit_status(&app, "restarting");
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_BACKOFF);
+        }
+    });
+}
+
+/// Spawns the sidecar and forwards its output until it terminates, or until
+/// the watchdog decides it's wedged and force-kills it.
+async fn run_until_exit(app: &AppHandle, config: &Arc<Mutex<Config>>) -> Result<(), String> {
+    let output_dir = config.lock().await.output_dir.clone();
+
+    let mut sidecar = app
+        .shell()
+        .sidecar("omarchyflow-backend")
+        .map_err(|e| format!("Failed to create sidecar: {}", e))?;
+    if let Some(dir) = &output_dir {
+        sidecar = sidecar.env(OUTPUT_DIR_ENV, dir.to_string_lossy().to_string());
+    }
+
+    let (mut rx, child) = sidecar
+        .spawn()
+        .map_err(|e| format!("Failed to spawn sidecar: {}", e))?;
+
+    socket_client::reset_consecutive_failures();
+    emit_status(app, "ready");
+
+    let mut watchdog = interval(WATCHDOG_INTERVAL);
+    watchdog.tick().await; // first tick fires immediately; skip it
+
+    loop {
+        tokio::select! {
+            event = rx.recv() => {
+                match event {
+                    Some(CommandEvent::Stdout(line)) => {
+                        log::info!("Backend: {}", String::from_utf8_lossy(&line));
+                    }
+                    Some(CommandEvent::Stderr(line)) => {
+                        log::warn!("Backend: {}", String::from_utf8_lossy(&line));
+                    }
+                    Some(CommandEvent::Terminated(payload)) => {
+                        return match payload.code {
+                            Some(0) => Ok(()),
+                            code => Err(format!("exited with code {:?}", code)),
+                        };
+                    }
+                    Some(_) => {}
+                    None => return Ok(()),
+                }
+            }
+            _ = watchdog.tick() => {
+                let failures = socket_client::consecutive_failures();
+                if failures >= socket_client::FAILURE_THRESHOLD {
+                    log::warn!(
+                        "Backend unresponsive for {} consecutive command(s); forcing restart",
+                        failures
+                    );
+                    if let Err(e) = child.kill() {
+                        log::warn!("Failed to kill wedged backend: {}", e);
+                    }
+                    return Err("backend stopped responding to commands".to_string());
+                }
+            }
+        }
+    }
+}
+
+/// Emits a `backend-status` event for the webview (starting/ready/crashed/restarting).
+fn emit_status(app: &AppHandle, status: &str) {
+    log::info!("Backend status: {}", status);
+    let _ = app.emit("backend-status", status);
+}
@@ -0,0 +1,102 @@
+/// Voice-activated recording: watches the shared microphone level from
+/// `audio_meter` and applies attack/release hysteresis so brief dips or
+/// spikes don't chatter recording on and off.
+use crate::audio_meter::AudioLevelState;
+use crate::config::Config;
+use crate::events::BackendEvent;
+use crate::{start_if_stopped, stop_if_recording, RecordingState};
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Mutex;
+use tokio::time::{interval, Duration, Instant};
+
+/// How often the level is sampled by the attack/release state machine.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Starts the voice-activity-detection loop for the lifetime of the app. It
+/// polls `config.auto_record` on every tick so the feature can be toggled
+/// live via `set_config` without restarting anything.
+pub fn start(
+    app: AppHandle,
+    level_state: Arc<AudioLevelState>,
+    recording_state: Arc<Mutex<RecordingState>>,
+    config: Arc<Mutex<Config>>,
+) {
+    tauri::async_runtime::spawn(async move {
+        let mut ticker = interval(POLL_INTERVAL);
+        let mut above_since: Option<Instant> = None;
+        let mut below_since: Option<Instant> = None;
+        let mut was_armed = false;
+
+        loop {
+            ticker.tick().await;
+
+            let (threshold, attack, release, auto_record) = {
+                let config = config.lock().await;
+                (
+                    config.mic_threshold,
+                    Duration::from_millis(config.attack_ms),
+                    Duration::from_millis(config.release_ms),
+                    config.auto_record,
+                )
+            };
+
+            if !auto_record {
+                if was_armed {
+                    // Falling edge: we were armed (at least "listening") and
+                    // auto-record just got turned off. Tell the frontend so
+                    // it doesn't keep showing "listening" forever.
+                    emit(&app, "idle");
+                }
+                above_since = None;
+                below_since = None;
+                was_armed = false;
+                continue;
+            }
+
+            if !was_armed {
+                was_armed = true;
+                emit(&app, "listening");
+            }
+
+            let level = *level_state.0.lock().await;
+            let now = Instant::now();
+            let is_recording = recording_state.lock().await.is_recording;
+
+            // start_if_stopped/stop_if_recording hold the lock across
+            // send_command, so these can't race a hotkey press or IPC
+            // toggle into double-firing "start"/"stop".
+            if level >= threshold {
+                below_since = None;
+                let since = *above_since.get_or_insert(now);
+                if !is_recording
+                    && now.duration_since(since) >= attack
+                    && start_if_stopped(&recording_state).await
+                {
+                    emit(&app, "recording");
+                }
+            } else {
+                above_since = None;
+                let since = *below_since.get_or_insert(now);
+                if is_recording
+                    && now.duration_since(since) >= release
+                    && stop_if_recording(&recording_state).await
+                {
+                    emit(&app, "listening");
+                }
+            }
+        }
+    });
+}
+
+/// Forwards a VAD state transition through the same `backend-event` channel
+/// used for real backend status pushes.
+fn emit(app: &AppHandle, name: &str) {
+    let _ = app.emit(
+        "backend-event",
+        &BackendEvent {
+            name: name.to_string(),
+            payload: None,
+        },
+    );
+}